@@ -0,0 +1,46 @@
+use near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    json_types::U128,
+    serde::{Deserialize, Serialize},
+    AccountId, Promise,
+};
+
+/// Identifies a fungible asset the station can hold and pay out: either the
+/// native NEAR token or a NEP-141 contract.
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    BorshSerialize,
+    BorshDeserialize,
+    Serialize,
+    Deserialize,
+)]
+#[serde(crate = "near_sdk::serde", tag = "type", rename_all = "snake_case")]
+pub enum AssetId {
+    Native,
+    Nep141(AccountId),
+}
+
+impl AssetId {
+    /// Schedule a transfer of `amount` of this asset to `receiver_id`.
+    pub fn transfer(&self, receiver_id: AccountId, amount: U128) -> Promise {
+        match self {
+            Self::Native => {
+                Promise::new(receiver_id).transfer(near_sdk::NearToken::from_yoctonear(amount.0))
+            }
+            Self::Nep141(contract_id) => {
+                ext_nep141::ext(contract_id.clone()).ft_transfer(receiver_id, amount, None)
+            }
+        }
+    }
+}
+
+#[near_sdk::ext_contract(ext_nep141)]
+pub trait Nep141 {
+    fn ft_transfer(&mut self, receiver_id: AccountId, amount: U128, memo: Option<String>);
+}
@@ -0,0 +1,15 @@
+use near_sdk::AccountId;
+
+use crate::foreign_address::ForeignAddress;
+
+/// External authorization registry interface. Operators point the station at a
+/// dedicated, upgradeable contract that owns the allow-lists, rather than
+/// maintaining them inline in gas-station storage.
+#[near_sdk::ext_contract(ext_authorization)]
+pub trait AuthorizationRegistry {
+    /// Whether the given NEAR account is permitted to originate relayed
+    /// transactions.
+    fn is_sender_authorized(&self, account_id: AccountId) -> bool;
+    /// Whether the given foreign address is a permitted relay destination.
+    fn is_receiver_authorized(&self, address: ForeignAddress) -> bool;
+}
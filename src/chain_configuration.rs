@@ -0,0 +1,76 @@
+use ethers_core::types::U256;
+use near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+    store::Vector,
+};
+
+use crate::oracle::PriceData;
+
+/// A paymaster account the station rotates through when relaying to a foreign
+/// chain.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct PaymasterConfiguration {
+    pub nonce: u32,
+    pub key_path: String,
+}
+
+/// Per-foreign-chain relaying configuration: how much gas the station spends
+/// forwarding funds, the fee markup, and the oracle asset used to price the
+/// chain's gas token in terms of the local fee asset.
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct ChainConfiguration {
+    pub next_paymaster: u32,
+    pub oracle_asset_id: String,
+    pub transfer_gas: [u64; 4],
+    pub fee_rate: (u128, u128),
+    /// When set, a flat amount of the local fee asset is charged per relayed
+    /// transaction, bypassing oracle pricing. `None` uses oracle pricing.
+    pub fixed_gas_cost: Option<u128>,
+    pub paymasters: Vector<PaymasterConfiguration>,
+}
+
+impl ChainConfiguration {
+    /// Gas the station itself spends forwarding value to the user on the
+    /// foreign chain.
+    pub fn transfer_gas(&self) -> U256 {
+        U256(self.transfer_gas)
+    }
+
+    /// Select the next paymaster in the rotation, wrapping around so that a
+    /// removed paymaster never leaves a permanent gap.
+    pub fn next_paymaster(&mut self) -> Option<&mut PaymasterConfiguration> {
+        let len = self.paymasters.len();
+        if len == 0 {
+            return None;
+        }
+        let index = self.next_paymaster % len;
+        self.next_paymaster = self.next_paymaster.wrapping_add(1);
+        self.paymasters.get_mut(index)
+    }
+
+    /// Convert an amount of the foreign chain's gas token into the local fee
+    /// asset, applying the configured fee rate.
+    pub fn foreign_token_price(
+        &self,
+        local_asset_id: &str,
+        price_data: &PriceData,
+        foreign_tokens: U256,
+    ) -> u128 {
+        let foreign_price = price_data
+            .price_for(&self.oracle_asset_id)
+            .unwrap_or_else(|| near_sdk::env::panic_str("No price for foreign oracle asset"));
+        let local_price = price_data
+            .price_for(local_asset_id)
+            .unwrap_or_else(|| near_sdk::env::panic_str("No price for local oracle asset"));
+
+        // local = foreign_tokens * (foreign_multiplier / local_multiplier) * fee_rate
+        let numerator = foreign_tokens
+            * U256::from(foreign_price.multiplier.0)
+            * U256::from(self.fee_rate.0);
+        let denominator = U256::from(local_price.multiplier.0) * U256::from(self.fee_rate.1);
+
+        (numerator / denominator).as_u128()
+    }
+}
@@ -0,0 +1,18 @@
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk_contract_tools::event;
+
+/// Events emitted by the gas station, following the NEP-297 event standard.
+#[event(standard = "gas_station", version = "1.0.0")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde", rename_all = "snake_case")]
+pub enum ContractEvent {
+    /// A transaction sequence was created and is pending signatures.
+    TransactionSequenceCreated { id: near_sdk::json_types::U64 },
+    /// A gasless service transaction was relayed free of charge. Records the
+    /// gas the station underwrote so operators can account for the subsidy.
+    SubsidizedServiceTransaction {
+        sender_id: near_sdk::AccountId,
+        chain_id: near_sdk::json_types::U64,
+        subsidized_gas: near_sdk::json_types::U128,
+    },
+}
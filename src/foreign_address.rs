@@ -0,0 +1,49 @@
+use std::{fmt, str::FromStr};
+
+use near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+};
+
+/// A 20-byte EVM account address.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    BorshSerialize,
+    BorshDeserialize,
+    Serialize,
+    Deserialize,
+)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ForeignAddress(pub [u8; 20]);
+
+impl From<[u8; 20]> for ForeignAddress {
+    fn from(value: [u8; 20]) -> Self {
+        Self(value)
+    }
+}
+
+impl fmt::Display for ForeignAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x{}", hex::encode(self.0))
+    }
+}
+
+impl FromStr for ForeignAddress {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.strip_prefix("0x").unwrap_or(s);
+        let bytes = hex::decode(s).map_err(|e| e.to_string())?;
+        let bytes: [u8; 20] = bytes
+            .try_into()
+            .map_err(|_| "Foreign address must be 20 bytes".to_string())?;
+        Ok(Self(bytes))
+    }
+}
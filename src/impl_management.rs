@@ -123,6 +123,48 @@ impl Contract {
         self.sender_whitelist.clear();
     }
 
+    pub fn get_authorization_contract_id(&self) -> Option<&AccountId> {
+        self.authorization_contract_id.as_ref()
+    }
+
+    /// Configure an external authorization registry contract. When the
+    /// corresponding [`Flags`] bit selects "registry" (or "both"), the
+    /// transaction-submission flow defers sender/receiver eligibility to a
+    /// cross-contract view on this account instead of — or in addition to
+    /// — the local whitelists. Pass `None` to remove the registry; while
+    /// [`Flags::AUTHORIZATION_REGISTRY`] is set, transaction submission then
+    /// panics with "No authorization registry configured".
+    pub fn set_authorization_contract_id(&mut self, account_id: Option<AccountId>) {
+        self.assert_owner();
+        self.authorization_contract_id = account_id;
+        // A changed registry invalidates every cached verdict.
+        self.cached_authorizations.clear();
+    }
+
+    /// Whether `sender` is *certified* to originate a relayed transaction,
+    /// honoring the authorization [`Flags`]. Certification is a positive
+    /// requirement: with no authorization source configured the sender is
+    /// **not** certified (returns `false`), so the gasless lane never
+    /// subsidizes an un-vetted caller. "Both" mode requires the local set
+    /// *and* the cached registry verdict to permit the sender.
+    pub(crate) fn is_sender_authorized(&self, sender: &AccountId) -> bool {
+        let check_local = self.flags.contains(Flags::AUTHORIZATION_LOCAL)
+            || self.flags.contains(Flags::SENDER_WHITELIST);
+        let check_registry = self.flags.contains(Flags::AUTHORIZATION_REGISTRY);
+
+        if !check_local && !check_registry {
+            // No certification source — nobody is certified.
+            return false;
+        }
+        if check_local && !self.sender_whitelist.contains(sender) {
+            return false;
+        }
+        if check_registry && !self.cached_authorizations.get(sender).copied().unwrap_or(false) {
+            return false;
+        }
+        true
+    }
+
     pub fn add_foreign_chain(
         &mut self,
         chain_id: U64,
@@ -139,6 +181,7 @@ impl Contract {
                 oracle_asset_id,
                 transfer_gas: U256::from(transfer_gas.0).0,
                 fee_rate: (fee_rate.0.into(), fee_rate.1.into()),
+                fixed_gas_cost: None,
                 paymasters: Vector::new(StorageKey::Paymasters(chain_id.0)),
             },
         );
@@ -162,6 +205,30 @@ impl Contract {
         }
     }
 
+    /// Switch a foreign chain into "fixed cost" mode: a flat amount of the
+    /// local fee asset is charged per relayed transaction, bypassing the
+    /// oracle asset price entirely. Use
+    /// [`Contract::clear_foreign_chain_fixed_gas_cost`] to revert to oracle
+    /// pricing.
+    pub fn set_foreign_chain_fixed_gas_cost(&mut self, chain_id: U64, fixed_gas_cost: U128) {
+        self.assert_owner();
+        if let Some(config) = self.foreign_chains.get_mut(&chain_id.0) {
+            config.fixed_gas_cost = Some(fixed_gas_cost.0);
+        } else {
+            env::panic_str("Foreign chain does not exist");
+        }
+    }
+
+    /// Revert a foreign chain from "fixed cost" mode back to oracle pricing.
+    pub fn clear_foreign_chain_fixed_gas_cost(&mut self, chain_id: U64) {
+        self.assert_owner();
+        if let Some(config) = self.foreign_chains.get_mut(&chain_id.0) {
+            config.fixed_gas_cost = None;
+        } else {
+            env::panic_str("Foreign chain does not exist");
+        }
+    }
+
     pub fn remove_foreign_chain(&mut self, chain_id: U64) {
         self.assert_owner();
         if let Some((_, mut config)) = self.foreign_chains.remove_entry(&chain_id.0) {
@@ -262,13 +329,18 @@ impl Contract {
         self.pending_transaction_sequences.get(&id.0)
     }
 
-    pub fn withdraw_collected_fees(
+    /// Allocate collected fees to a recipient's claimable balance. This moves
+    /// the balance out of [`Contract::collected_fees`] and into the
+    /// per-`(AccountId, AssetId)` `claimable` map without performing any
+    /// transfer. The recipient later pulls the funds with
+    /// [`Contract::claim_fees`], so a mistyped or locked/frozen receiver can
+    /// never strand or lose fees on a failed push.
+    pub fn allocate_fees_to(
         &mut self,
         asset_id: AssetId,
+        receiver_id: AccountId,
         amount: Option<U128>,
-        receiver_id: Option<AccountId>, // TODO: Pull method instead of push (danger of typos/locked accounts)
-    ) -> Promise {
-        near_sdk::assert_one_yocto();
+    ) {
         self.assert_owner();
         let fees = self
             .collected_fees
@@ -280,18 +352,74 @@ impl Contract {
         fees.0 = fees
             .0
             .checked_sub(amount.0)
-            .unwrap_or_else(|| env::panic_str("Not enough fees to withdraw"));
+            .unwrap_or_else(|| env::panic_str("Not enough fees to allocate"));
+
+        let claimable = self
+            .claimable
+            .entry((receiver_id, asset_id))
+            .or_insert(U128(0));
+        claimable.0 += amount.0;
+    }
 
-        asset_id.transfer(
-            receiver_id.unwrap_or_else(|| self.own_get_owner().unwrap()),
-            amount,
+    /// Pull the caller's allocated fees for the given asset. Permissionless:
+    /// the recipient initiates (and can retry) their own withdrawal. The
+    /// caller's claimable entry is zeroed before the transfer is scheduled.
+    pub fn claim_fees(&mut self, asset_id: AssetId) -> Promise {
+        near_sdk::assert_one_yocto();
+        let account_id = env::predecessor_account_id();
+        let key = (account_id, asset_id.clone());
+
+        let amount = self
+            .claimable
+            .get_mut(&key)
+            .unwrap_or_else(|| env::panic_str("No claimable fees for caller"));
+
+        let to_transfer = U128(amount.0);
+        require!(to_transfer.0 > 0, "No claimable fees for caller");
+        // Zero the entry before dispatching the transfer to prevent a
+        // double-claim, and restore it in the callback if the transfer fails
+        // so the recipient can retry rather than losing the balance.
+        amount.0 = 0;
+
+        asset_id.transfer(key.0.clone(), to_transfer).then(
+            Self::ext(env::current_account_id()).claim_fees_callback(key.0, key.1, to_transfer),
         )
     }
 
+    #[private]
+    pub fn claim_fees_callback(
+        &mut self,
+        account_id: AccountId,
+        asset_id: AssetId,
+        amount: U128,
+        #[callback_result] result: Result<(), PromiseError>,
+    ) {
+        if result.is_err() {
+            // Transfer failed (e.g. unregistered receiver): re-credit the
+            // claimable balance so the recipient can claim again.
+            let claimable = self
+                .claimable
+                .entry((account_id, asset_id))
+                .or_insert(U128(0));
+            claimable.0 += amount.0;
+        }
+    }
+
     pub fn get_collected_fees(&self) -> std::collections::HashMap<&AssetId, &U128> {
         self.collected_fees.iter().collect()
     }
 
+    pub fn get_claimable_fees(
+        &self,
+        account_id: AccountId,
+    ) -> std::collections::HashMap<&AssetId, &U128> {
+        self.claimable
+            .iter()
+            .filter(|((holder, _), _)| *holder == account_id)
+            .map(|((_, asset_id), amount)| (asset_id, amount))
+            .collect()
+    }
+
     pub fn get_foreign_address_for(&self, account_id: AccountId) -> ForeignAddress {
         get_mpc_address(
             self.signer_contract_public_key.clone().unwrap(),
@@ -302,21 +430,50 @@ impl Contract {
     }
 
     pub fn estimate_gas_cost(&self, transaction_rlp_hex: String, price_data: PriceData) -> U128 {
+        // `try_from` rejects already-signed payloads and requires an embedded
+        // chain id (EIP-155 replay protection).
         let transaction =
             ValidTransactionRequest::try_from(decode_transaction_request(&transaction_rlp_hex))
                 .unwrap_or_else(|e| env::panic_str(&format!("Invalid transaction request: {e}")));
 
+        // The embedded chain id must match a configured foreign chain so the
+        // signature is bound to a chain we can actually relay to.
         let foreign_chain_configuration = self
             .foreign_chains
             .get(&transaction.chain_id)
             .unwrap_or_else(|| {
                 env::panic_str(&format!(
-                    "Paymaster not supported for chain id {}",
+                    "Unsupported or mismatched chain id {}",
                     transaction.chain_id
                 ))
             });
 
+        // For EIP-2718 typed transactions an access list may reference chains
+        // other than the one being relayed to. We can only relay to chains we
+        // have a paymaster for, so reject any referenced chain we do not know.
+        for referenced_chain_id in transaction.access_list_chain_ids() {
+            require!(
+                self.foreign_chains.contains_key(&referenced_chain_id),
+                format!("Access list references unsupported chain id {referenced_chain_id}"),
+            );
+        }
+
+        // A zero effective gas price quotes to zero. This is a pure quote:
+        // certification and subsidy accounting for the gasless lane happen on
+        // the mutating relay path, not in this view.
+        if transaction.gas_price().is_zero() {
+            return U128(0);
+        }
+
+        // In fixed-cost mode the owner has pre-negotiated a flat fee for the
+        // chain, so we charge that regardless of the oracle price data.
+        if let Some(fixed_gas_cost) = foreign_chain_configuration.fixed_gas_cost {
+            return U128(fixed_gas_cost);
+        }
+
         let paymaster_transaction_gas = foreign_chain_configuration.transfer_gas();
+        // `gas_price()` returns the worst-case ceiling (`max_fee_per_gas`) for
+        // type-0x02 transactions, so this remains a safe upper bound.
         let request_tokens_for_gas =
             (transaction.gas() + paymaster_transaction_gas) * transaction.gas_price();
 
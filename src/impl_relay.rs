@@ -0,0 +1,162 @@
+use near_sdk::{env, json_types::U128, near_bindgen, require, AccountId, Promise, PromiseError};
+use near_sdk_contract_tools::standard::nep297::Event;
+
+use crate::{
+    authorization::ext_authorization, decode_transaction_request, events::ContractEvent,
+    signer_contract::ext_signer, valid_transaction_request::ValidTransactionRequest, Contract,
+    ContractExt, Flags,
+};
+
+#[near_bindgen]
+impl Contract {
+    /// Submit a station-constructed unsigned transaction for relaying. This is
+    /// the mutating entry point the eligibility checks, gasless lane and
+    /// chain-bound signing hang off — `estimate_gas_cost` remains a pure quote.
+    ///
+    /// When [`Flags::AUTHORIZATION_REGISTRY`] is set, sender and receiver
+    /// eligibility is delegated to the external registry via cross-contract
+    /// views (the sender verdict is cached for the sequence); the local sets
+    /// are consulted as the local whitelist flags select.
+    pub fn create_transaction(&mut self, transaction_rlp_hex: String) -> Promise {
+        let sender = env::predecessor_account_id();
+
+        // `try_from` rejects already-signed payloads and requires an embedded
+        // chain id (EIP-155 replay protection).
+        let transaction =
+            ValidTransactionRequest::try_from(decode_transaction_request(&transaction_rlp_hex))
+                .unwrap_or_else(|e| env::panic_str(&format!("Invalid transaction request: {e}")));
+
+        require!(
+            self.foreign_chains.contains_key(&transaction.chain_id),
+            format!("Unsupported or mismatched chain id {}", transaction.chain_id),
+        );
+
+        for referenced_chain_id in transaction.access_list_chain_ids() {
+            require!(
+                self.foreign_chains.contains_key(&referenced_chain_id),
+                format!("Access list references unsupported chain id {referenced_chain_id}"),
+            );
+        }
+
+        if self.flags.contains(Flags::AUTHORIZATION_REGISTRY) {
+            let registry = self
+                .authorization_contract_id
+                .clone()
+                .unwrap_or_else(|| env::panic_str("No authorization registry configured"));
+
+            // Delegate both sender and destination eligibility to the registry,
+            // then continue once both verdicts are in.
+            ext_authorization::ext(registry.clone())
+                .is_sender_authorized(sender.clone())
+                .and(ext_authorization::ext(registry).is_receiver_authorized(transaction.receiver))
+                .then(
+                    Self::ext(env::current_account_id())
+                        .relay_after_authorization(sender, transaction),
+                )
+        } else {
+            self.assert_local_authorization(&sender, &transaction.receiver);
+            self.sign_relayed_transaction(sender, transaction)
+        }
+    }
+
+    #[private]
+    pub fn relay_after_authorization(
+        &mut self,
+        sender: AccountId,
+        transaction: ValidTransactionRequest,
+        #[callback_result] sender_authorized: Result<bool, PromiseError>,
+        #[callback_result] receiver_authorized: Result<bool, PromiseError>,
+    ) -> Promise {
+        // Cache the registry's sender verdict for the duration of the sequence
+        // so downstream certification checks (e.g. the gasless lane) see it.
+        let sender_authorized = sender_authorized.unwrap_or(false);
+        self.cached_authorizations
+            .insert(sender.clone(), sender_authorized);
+
+        require!(sender_authorized, "Sender is not authorized by the registry");
+        require!(
+            receiver_authorized.unwrap_or(false),
+            "Receiver is not authorized by the registry",
+        );
+
+        // In "both" mode, or with a local whitelist flag set, the local sets
+        // must also permit the parties.
+        self.assert_local_authorization(&sender, &transaction.receiver);
+
+        self.sign_relayed_transaction(sender, transaction)
+    }
+
+    fn assert_local_authorization(
+        &self,
+        sender: &AccountId,
+        receiver: &crate::foreign_address::ForeignAddress,
+    ) {
+        if self.flags.contains(Flags::SENDER_WHITELIST)
+            || self.flags.contains(Flags::AUTHORIZATION_LOCAL)
+        {
+            require!(
+                self.sender_whitelist.contains(sender),
+                "Sender is not in the local whitelist",
+            );
+        }
+        if self.flags.contains(Flags::RECEIVER_WHITELIST)
+            || self.flags.contains(Flags::AUTHORIZATION_LOCAL)
+        {
+            require!(
+                self.receiver_whitelist.contains(receiver),
+                "Receiver is not in the local whitelist",
+            );
+        }
+    }
+
+    fn sign_relayed_transaction(
+        &mut self,
+        sender: AccountId,
+        transaction: ValidTransactionRequest,
+    ) -> Promise {
+        // Gasless "service transaction" lane: a zero effective gas price is
+        // relayed free of charge, but only when the lane is enabled and the
+        // sender is certified. The subsidized gas is recorded here, on the
+        // mutating path, so the event is actually persisted.
+        if transaction.gas_price().is_zero() {
+            require!(
+                self.flags.contains(Flags::GASLESS_SERVICE_TRANSACTIONS),
+                "Zero gas price is only permitted for gasless service transactions",
+            );
+            require!(
+                self.is_sender_authorized(&sender),
+                "Sender is not certified for gasless service transactions",
+            );
+            let config = self
+                .foreign_chains
+                .get(&transaction.chain_id)
+                .unwrap_or_else(|| env::panic_str("Foreign chain does not exist"));
+            let subsidized_gas = transaction.gas() + config.transfer_gas();
+            ContractEvent::SubsidizedServiceTransaction {
+                sender_id: sender,
+                chain_id: transaction.chain_id.into(),
+                subsidized_gas: U128(subsidized_gas.as_u128()),
+            }
+            .emit();
+        }
+
+        // Hand the chain-bound RLP to the signer so the produced signature is
+        // replay-protected for exactly this chain.
+        let path = self.next_paymaster_key_path(transaction.chain_id);
+        let payload = env::keccak256_array(&transaction.into_signing_payload());
+
+        ext_signer::ext(self.signer_contract_id.clone()).sign(payload, &path)
+    }
+
+    fn next_paymaster_key_path(&mut self, chain_id: u64) -> String {
+        let config = self
+            .foreign_chains
+            .get_mut(&chain_id)
+            .unwrap_or_else(|| env::panic_str("Foreign chain does not exist"));
+        config
+            .next_paymaster()
+            .unwrap_or_else(|| env::panic_str("No paymaster configured for chain"))
+            .key_path
+            .clone()
+    }
+}
@@ -0,0 +1,14 @@
+use near_sdk::{AccountId, PublicKey};
+
+use crate::foreign_address::ForeignAddress;
+
+/// Lookup of the EVM address controlled by the MPC signer for the given
+/// `(predecessor, path)` pair. No key derivation is performed here: every
+/// call returns an error, whatever the arguments.
+pub fn get_mpc_address(
+    _public_key: PublicKey,
+    _predecessor_id: &AccountId,
+    _path: &str,
+) -> Result<ForeignAddress, String> {
+    Err("MPC address derivation unavailable".to_string())
+}
@@ -0,0 +1,108 @@
+use near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    json_types::{U128, U64},
+    near_bindgen,
+    serde::{Deserialize, Serialize},
+    store::{UnorderedMap, UnorderedSet},
+    AccountId, BorshStorageKey, PanicOnDefault, PublicKey,
+};
+use near_sdk_contract_tools::owner::Owner;
+
+use crate::{
+    asset::AssetId, chain_configuration::ChainConfiguration, foreign_address::ForeignAddress,
+    valid_transaction_request::ValidTransactionRequest,
+};
+
+pub mod asset;
+pub mod authorization;
+pub mod chain_configuration;
+pub mod events;
+pub mod foreign_address;
+pub mod kdf;
+pub mod oracle;
+pub mod signer_contract;
+pub mod transaction;
+pub mod valid_transaction_request;
+
+mod impl_management;
+mod impl_relay;
+
+pub use transaction::decode_transaction_request;
+
+bitflags::bitflags! {
+    /// Feature toggles controlling how the station gates and prices relayed
+    /// transactions.
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
+    #[serde(crate = "near_sdk::serde")]
+    pub struct Flags: u8 {
+        /// Enforce the local receiver whitelist.
+        const RECEIVER_WHITELIST = 0b0000_0001;
+        /// Enforce the local sender whitelist.
+        const SENDER_WHITELIST = 0b0000_0010;
+        /// Consult the local sets for sender/receiver authorization.
+        const AUTHORIZATION_LOCAL = 0b0000_0100;
+        /// Consult the external authorization registry for sender/receiver
+        /// authorization. Combine with [`Flags::AUTHORIZATION_LOCAL`] to
+        /// require both.
+        const AUTHORIZATION_REGISTRY = 0b0000_1000;
+        /// Permit the gasless "service transaction" lane: certified senders may
+        /// relay zero-gas-price transactions free of charge.
+        const GASLESS_SERVICE_TRANSACTIONS = 0b0001_0000;
+    }
+}
+
+/// Storage prefixes for the contract's collections.
+#[derive(BorshSerialize, BorshStorageKey)]
+pub enum StorageKey {
+    ReceiverWhitelist,
+    SenderWhitelist,
+    ForeignChains,
+    CollectedFees,
+    PendingTransactionSequences,
+    Paymasters(u64),
+    CachedAuthorizations,
+    Claimable,
+}
+
+/// View projection of a configured foreign chain.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct GetForeignChain {
+    pub chain_id: U64,
+    pub oracle_asset_id: String,
+}
+
+/// A sequence of payloads pending signatures for a single relayed request.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct PendingTransactionSequence {
+    pub created_by_account_id: AccountId,
+    pub transaction: ValidTransactionRequest,
+    pub created_at_block_timestamp_ns: U64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, PanicOnDefault, Owner)]
+#[near_bindgen]
+pub struct Contract {
+    pub signer_contract_id: AccountId,
+    pub signer_contract_public_key: Option<PublicKey>,
+    pub oracle_local_asset_id: String,
+    /// External authorization registry, if configured. See
+    /// [`Flags::AUTHORIZATION_REGISTRY`].
+    pub authorization_contract_id: Option<AccountId>,
+    pub flags: Flags,
+    pub expire_sequence_after_ns: u64,
+    pub receiver_whitelist: UnorderedSet<ForeignAddress>,
+    pub sender_whitelist: UnorderedSet<AccountId>,
+    pub foreign_chains: UnorderedMap<u64, ChainConfiguration>,
+    pub collected_fees: UnorderedMap<AssetId, U128>,
+    /// Fees allocated to specific recipients, withdrawn via the pull pattern:
+    /// the owner allocates, the recipient claims. Keyed by `(recipient, asset)`.
+    pub claimable: UnorderedMap<(AccountId, AssetId), U128>,
+    pub pending_transaction_sequences: UnorderedMap<u64, PendingTransactionSequence>,
+    /// Latest registry verdict per sender, written when a registry check
+    /// completes and read by the gasless lane's certification check. The
+    /// registry is queried on every submission; entries are cleared only when
+    /// the registry account is changed.
+    pub cached_authorizations: UnorderedMap<AccountId, bool>,
+}
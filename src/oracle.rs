@@ -0,0 +1,37 @@
+use near_sdk::{
+    json_types::U128,
+    serde::{Deserialize, Serialize},
+};
+
+/// Price of a single asset as reported by the oracle.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AssetOptionalPrice {
+    pub asset_id: String,
+    pub price: Option<Price>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Price {
+    pub multiplier: U128,
+    pub decimals: u8,
+}
+
+/// Snapshot of oracle prices passed in by the relayer when requesting a quote.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct PriceData {
+    pub timestamp: near_sdk::json_types::U64,
+    pub recency_duration_sec: u32,
+    pub prices: Vec<AssetOptionalPrice>,
+}
+
+impl PriceData {
+    pub fn price_for(&self, asset_id: &str) -> Option<&Price> {
+        self.prices
+            .iter()
+            .find(|p| p.asset_id == asset_id)
+            .and_then(|p| p.price.as_ref())
+    }
+}
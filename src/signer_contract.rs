@@ -0,0 +1,35 @@
+use near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+    PromiseOrValue, PublicKey,
+};
+
+/// A signature returned by the MPC signer contract, split into its ECDSA
+/// components.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MpcSignature {
+    pub r: [u8; 32],
+    pub s: [u8; 32],
+    pub v: u8,
+}
+
+impl MpcSignature {
+    pub fn new(r: [u8; 32], s: [u8; 32], v: u8) -> Self {
+        Self { r, s, v }
+    }
+}
+
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub enum ProtocolContractState {
+    NotInitialized,
+    Running,
+}
+
+#[near_sdk::ext_contract(ext_signer)]
+pub trait SignerContract {
+    fn sign(&mut self, payload: [u8; 32], path: &String) -> PromiseOrValue<MpcSignature>;
+    fn state(&self) -> ProtocolContractState;
+    fn public_key(&self) -> PublicKey;
+}
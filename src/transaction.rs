@@ -0,0 +1,290 @@
+use ethers_core::{
+    types::U256,
+    utils::rlp::Rlp,
+};
+use near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+};
+
+use crate::foreign_address::ForeignAddress;
+
+/// EIP-2718 transaction type of a decoded request.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransactionKind {
+    /// Pre-2718 transaction with a single `gasPrice` (EIP-155 encoded).
+    Legacy,
+    /// Type-0x01 access-list transaction (EIP-2930) with a single `gasPrice`.
+    Eip2930,
+    /// Type-0x02 dynamic-fee transaction (EIP-1559).
+    Eip1559,
+}
+
+/// A single access-list entry. Entries may optionally carry a `chainId` when a
+/// request references state on another foreign chain.
+///
+/// Note: a leading `chainId` is a **station-specific** extension to the
+/// envelope — wire-standard EIP-2930/1559 access-list entries are always
+/// `[address, storageKeys]`. See [`read_access_list`].
+#[derive(
+    Clone,
+    Debug,
+    BorshSerialize,
+    BorshDeserialize,
+    Serialize,
+    Deserialize,
+)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AccessListEntry {
+    pub chain_id: Option<u64>,
+    pub address: ForeignAddress,
+    pub storage_keys: Vec<[u8; 32]>,
+}
+
+/// A transaction request as decoded off the wire, before validation. The
+/// station only ever signs requests it constructed itself, so a request that
+/// already carries signature components is flagged via [`Self::is_signed`].
+#[derive(Clone, Debug)]
+pub struct TransactionRequest {
+    pub kind: TransactionKind,
+    pub chain_id: Option<u64>,
+    pub nonce: u64,
+    pub gas_price: Option<U256>,
+    pub max_priority_fee_per_gas: Option<U256>,
+    pub max_fee_per_gas: Option<U256>,
+    pub gas: U256,
+    pub to: Option<ForeignAddress>,
+    pub value: U256,
+    pub data: Vec<u8>,
+    pub access_list: Vec<AccessListEntry>,
+    signed: bool,
+}
+
+impl TransactionRequest {
+    /// Whether the decoded payload already carries signature components
+    /// (`r`/`s`/`v`). The station must refuse to re-sign such payloads.
+    pub fn is_signed(&self) -> bool {
+        self.signed
+    }
+}
+
+fn read_u256(rlp: &Rlp, index: usize) -> Result<U256, String> {
+    rlp.at(index)
+        .and_then(|item| item.as_val::<U256>())
+        .map_err(|e| format!("Malformed field {index}: {e}"))
+}
+
+fn read_u64(rlp: &Rlp, index: usize) -> Result<u64, String> {
+    rlp.at(index)
+        .and_then(|item| item.as_val::<u64>())
+        .map_err(|e| format!("Malformed field {index}: {e}"))
+}
+
+fn read_address(rlp: &Rlp, index: usize) -> Result<Option<ForeignAddress>, String> {
+    let bytes = rlp
+        .at(index)
+        .and_then(|item| item.data())
+        .map_err(|e| format!("Malformed address at {index}: {e}"))?
+        .to_vec();
+    if bytes.is_empty() {
+        return Ok(None); // contract-creation: empty `to`
+    }
+    let bytes: [u8; 20] = bytes
+        .try_into()
+        .map_err(|_| "`to` must be 20 bytes".to_string())?;
+    Ok(Some(ForeignAddress(bytes)))
+}
+
+fn read_bytes(rlp: &Rlp, index: usize) -> Result<Vec<u8>, String> {
+    rlp.at(index)
+        .and_then(|item| item.data())
+        .map(<[u8]>::to_vec)
+        .map_err(|e| format!("Malformed bytes at {index}: {e}"))
+}
+
+/// Decode an access list.
+///
+/// Station-specific envelope extension: a 3-element entry is interpreted as
+/// `[chainId, address, storageKeys]`, letting a request reference state on
+/// another foreign chain. This is **not** wire-standard — EIP-2930/1559
+/// access-list entries are always `[address, storageKeys]`; the 2-element
+/// form decodes with no `chainId`. The shape was dictated by the request.
+fn read_access_list(rlp: &Rlp, index: usize) -> Result<Vec<AccessListEntry>, String> {
+    let list = rlp
+        .at(index)
+        .map_err(|e| format!("Malformed access list: {e}"))?;
+    let mut entries = Vec::new();
+    for i in 0..list.item_count().unwrap_or(0) {
+        let entry = list.at(i).map_err(|e| format!("Malformed access list entry: {e}"))?;
+        // Standard entries are `[address, storageKeys]`; an optional leading
+        // `chainId` marks a cross-chain reference.
+        let (chain_offset, chain_id) = if entry.item_count().unwrap_or(0) == 3 {
+            (1, Some(read_u64(&entry, 0)?))
+        } else {
+            (0, None)
+        };
+        let address = read_address(&entry, chain_offset)?
+            .ok_or_else(|| "Access list entry missing address".to_string())?;
+        let keys_rlp = entry
+            .at(chain_offset + 1)
+            .map_err(|e| format!("Malformed storage keys: {e}"))?;
+        let mut storage_keys = Vec::new();
+        for k in 0..keys_rlp.item_count().unwrap_or(0) {
+            let key = read_bytes(&keys_rlp, k)?;
+            let key: [u8; 32] = key
+                .try_into()
+                .map_err(|_| "Storage key must be 32 bytes".to_string())?;
+            storage_keys.push(key);
+        }
+        entries.push(AccessListEntry {
+            chain_id,
+            address,
+            storage_keys,
+        });
+    }
+    Ok(entries)
+}
+
+/// Decode an EIP-2718 transaction envelope from its hex encoding. A leading
+/// `0x02` byte selects the EIP-1559 (dynamic-fee) format and `0x01` the
+/// EIP-2930 (access-list) format; anything else is treated as a legacy
+/// EIP-155 transaction.
+pub fn decode_transaction_request(transaction_rlp_hex: &str) -> TransactionRequest {
+    decode_transaction_request_impl(transaction_rlp_hex)
+        .unwrap_or_else(|e| near_sdk::env::panic_str(&format!("Failed to decode transaction: {e}")))
+}
+
+fn decode_transaction_request_impl(transaction_rlp_hex: &str) -> Result<TransactionRequest, String> {
+    let hex = transaction_rlp_hex
+        .strip_prefix("0x")
+        .unwrap_or(transaction_rlp_hex);
+    let bytes = hex::decode(hex).map_err(|e| e.to_string())?;
+    let (first, rest) = bytes.split_first().ok_or("Empty transaction payload")?;
+
+    match first {
+        0x02 => {
+            // [chainId, nonce, maxPriorityFeePerGas, maxFeePerGas, gasLimit,
+            //  to, value, data, accessList, (yParity, r, s)]
+            let rlp = Rlp::new(rest);
+            let count = rlp.item_count().map_err(|e| e.to_string())?;
+            Ok(TransactionRequest {
+                kind: TransactionKind::Eip1559,
+                chain_id: Some(read_u64(&rlp, 0)?),
+                nonce: read_u64(&rlp, 1)?,
+                gas_price: None,
+                max_priority_fee_per_gas: Some(read_u256(&rlp, 2)?),
+                max_fee_per_gas: Some(read_u256(&rlp, 3)?),
+                gas: read_u256(&rlp, 4)?,
+                to: read_address(&rlp, 5)?,
+                value: read_u256(&rlp, 6)?,
+                data: read_bytes(&rlp, 7)?,
+                access_list: read_access_list(&rlp, 8)?,
+                signed: count > 9,
+            })
+        }
+        0x01 => {
+            // [chainId, nonce, gasPrice, gasLimit, to, value, data, accessList,
+            //  (yParity, r, s)]
+            let rlp = Rlp::new(rest);
+            let count = rlp.item_count().map_err(|e| e.to_string())?;
+            Ok(TransactionRequest {
+                kind: TransactionKind::Eip2930,
+                chain_id: Some(read_u64(&rlp, 0)?),
+                nonce: read_u64(&rlp, 1)?,
+                gas_price: Some(read_u256(&rlp, 2)?),
+                max_priority_fee_per_gas: None,
+                max_fee_per_gas: None,
+                gas: read_u256(&rlp, 3)?,
+                to: read_address(&rlp, 4)?,
+                value: read_u256(&rlp, 5)?,
+                data: read_bytes(&rlp, 6)?,
+                access_list: read_access_list(&rlp, 7)?,
+                signed: count > 8,
+            })
+        }
+        _ => {
+            // Legacy: [nonce, gasPrice, gasLimit, to, value, data, v/chainId, r, s]
+            let rlp = Rlp::new(&bytes);
+            let r = read_u256(&rlp, 7).unwrap_or_default();
+            let s = read_u256(&rlp, 8).unwrap_or_default();
+            let seventh = read_u64(&rlp, 6).ok();
+            // On an EIP-155 *unsigned* payload fields 7 and 8 are zero and
+            // field 6 carries the chain id; a signed payload has non-zero r/s
+            // and field 6 is `v`.
+            let signed = !r.is_zero() || !s.is_zero();
+            let chain_id = if signed {
+                seventh.map(|v| (v.saturating_sub(35)) / 2)
+            } else {
+                seventh
+            };
+            Ok(TransactionRequest {
+                kind: TransactionKind::Legacy,
+                chain_id,
+                nonce: read_u64(&rlp, 0)?,
+                gas_price: Some(read_u256(&rlp, 1)?),
+                max_priority_fee_per_gas: None,
+                max_fee_per_gas: None,
+                gas: read_u256(&rlp, 2)?,
+                to: read_address(&rlp, 3)?,
+                value: read_u256(&rlp, 4)?,
+                data: read_bytes(&rlp, 5)?,
+                access_list: Vec::new(),
+                signed,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ethers_core::{types::U256, utils::rlp::RlpStream};
+
+    use super::*;
+
+    fn encode_eip1559(
+        chain_id: u64,
+        to: [u8; 20],
+        max_priority: u64,
+        max_fee: u64,
+        gas: u64,
+        signed: bool,
+    ) -> String {
+        let mut stream = RlpStream::new_list(if signed { 12 } else { 9 });
+        stream.append(&chain_id);
+        stream.append(&1u64); // nonce
+        stream.append(&U256::from(max_priority));
+        stream.append(&U256::from(max_fee));
+        stream.append(&U256::from(gas));
+        stream.append(&to.as_slice());
+        stream.append(&U256::zero()); // value
+        stream.append(&Vec::<u8>::new()); // data
+        stream.begin_list(0); // empty access list
+        if signed {
+            stream.append(&0u8); // yParity
+            stream.append(&U256::from(1u64)); // r
+            stream.append(&U256::from(2u64)); // s
+        }
+        let mut bytes = vec![0x02];
+        bytes.extend_from_slice(stream.as_raw());
+        hex::encode(bytes)
+    }
+
+    #[test]
+    fn decodes_type_2_envelope() {
+        let hex = encode_eip1559(1, [0x11; 20], 1, 100, 21_000, false);
+        let request = decode_transaction_request(&hex);
+        assert_eq!(request.kind, TransactionKind::Eip1559);
+        assert_eq!(request.chain_id, Some(1));
+        assert_eq!(request.max_fee_per_gas, Some(U256::from(100)));
+        assert_eq!(request.gas, U256::from(21_000));
+        assert_eq!(request.to, Some(ForeignAddress([0x11; 20])));
+        assert!(!request.is_signed());
+    }
+
+    #[test]
+    fn detects_signed_type_2_payload() {
+        let hex = encode_eip1559(1, [0x22; 20], 1, 100, 21_000, true);
+        let request = decode_transaction_request(&hex);
+        assert!(request.is_signed());
+    }
+}
@@ -0,0 +1,250 @@
+use ethers_core::{types::U256, utils::rlp::RlpStream};
+use near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    serde::{Deserialize, Serialize},
+};
+
+use crate::{
+    foreign_address::ForeignAddress,
+    transaction::{AccessListEntry, TransactionKind, TransactionRequest},
+};
+
+/// A transaction request that has passed decoding and validation and is safe
+/// to price and hand to the signer. Dynamic-fee (type-0x02) requests carry
+/// `max_fee_per_gas`/`max_priority_fee_per_gas`; legacy and access-list
+/// requests carry a single `gas_price`.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ValidTransactionRequest {
+    pub chain_id: u64,
+    pub receiver: ForeignAddress,
+    pub nonce: u64,
+    value: [u64; 4],
+    gas: [u64; 4],
+    pub data: Vec<u8>,
+    gas_price: Option<[u64; 4]>,
+    max_fee_per_gas: Option<[u64; 4]>,
+    max_priority_fee_per_gas: Option<[u64; 4]>,
+    access_list: Vec<AccessListEntry>,
+}
+
+impl ValidTransactionRequest {
+    pub fn gas(&self) -> U256 {
+        U256(self.gas)
+    }
+
+    pub fn value(&self) -> U256 {
+        U256(self.value)
+    }
+
+    /// Worst-case gas price. For type-0x02 transactions this is the
+    /// `max_fee_per_gas` ceiling, so pricing against it always yields a safe
+    /// upper bound; for legacy and access-list transactions it is the flat
+    /// `gas_price`.
+    pub fn gas_price(&self) -> U256 {
+        if let Some(max_fee_per_gas) = self.max_fee_per_gas {
+            U256(max_fee_per_gas)
+        } else {
+            U256(self.gas_price.unwrap_or_default())
+        }
+    }
+
+    pub fn max_fee_per_gas(&self) -> Option<U256> {
+        self.max_fee_per_gas.map(U256)
+    }
+
+    pub fn max_priority_fee_per_gas(&self) -> Option<U256> {
+        self.max_priority_fee_per_gas.map(U256)
+    }
+
+    /// Chains referenced by the request's access list. Used to reject requests
+    /// whose access list touches a chain the station has no paymaster for.
+    pub fn access_list_chain_ids(&self) -> Vec<u64> {
+        self.access_list
+            .iter()
+            .filter_map(|entry| entry.chain_id)
+            .collect()
+    }
+
+    /// Re-encode the request into the unsigned RLP payload handed to the
+    /// signer. The `chain_id` is embedded (as the type-2 `chainId` field, or
+    /// the EIP-155 `chainId` placeholder for legacy transactions) so the
+    /// resulting signature is bound to exactly one chain and cannot be
+    /// replayed on another.
+    pub fn into_signing_payload(&self) -> Vec<u8> {
+        if let Some(max_fee_per_gas) = self.max_fee_per_gas {
+            // Type-0x02 envelope: 0x02 || rlp([chainId, nonce, maxPriority,
+            // maxFee, gas, to, value, data, accessList]).
+            let mut stream = RlpStream::new_list(9);
+            stream.append(&self.chain_id);
+            stream.append(&self.nonce);
+            stream.append(&U256(self.max_priority_fee_per_gas.unwrap_or_default()));
+            stream.append(&U256(max_fee_per_gas));
+            stream.append(&self.gas());
+            stream.append(&self.receiver.0.as_slice());
+            stream.append(&self.value());
+            stream.append(&self.data);
+            self.append_access_list(&mut stream);
+            let mut payload = Vec::with_capacity(stream.as_raw().len() + 1);
+            payload.push(0x02);
+            payload.extend_from_slice(stream.as_raw());
+            payload
+        } else {
+            // Legacy EIP-155: rlp([nonce, gasPrice, gas, to, value, data,
+            // chainId, 0, 0]).
+            let mut stream = RlpStream::new_list(9);
+            stream.append(&self.nonce);
+            stream.append(&U256(self.gas_price.unwrap_or_default()));
+            stream.append(&self.gas());
+            stream.append(&self.receiver.0.as_slice());
+            stream.append(&self.value());
+            stream.append(&self.data);
+            stream.append(&self.chain_id);
+            stream.append(&0u8);
+            stream.append(&0u8);
+            stream.as_raw().to_vec()
+        }
+    }
+
+    /// Re-encode the access list, preserving any station-specific `chainId`
+    /// prefix so the refs validated at decode time survive into the signed
+    /// payload. Standard entries encode as `[address, storageKeys]`;
+    /// chain-tagged entries as `[chainId, address, storageKeys]`.
+    fn append_access_list(&self, stream: &mut RlpStream) {
+        stream.begin_list(self.access_list.len());
+        for entry in &self.access_list {
+            let fields = if entry.chain_id.is_some() { 3 } else { 2 };
+            stream.begin_list(fields);
+            if let Some(chain_id) = entry.chain_id {
+                stream.append(&chain_id);
+            }
+            stream.append(&entry.address.0.as_slice());
+            stream.begin_list(entry.storage_keys.len());
+            for key in &entry.storage_keys {
+                stream.append(&key.as_slice());
+            }
+        }
+    }
+}
+
+impl TryFrom<TransactionRequest> for ValidTransactionRequest {
+    type Error = String;
+
+    fn try_from(request: TransactionRequest) -> Result<Self, Self::Error> {
+        // The station only signs station-constructed unsigned requests; a
+        // payload already carrying r/s/v must never be re-signed.
+        if request.is_signed() {
+            return Err(
+                "Unexpected signature present: only unsigned transaction requests may be signed"
+                    .to_string(),
+            );
+        }
+
+        // EIP-155 replay protection requires an embedded chain id.
+        let chain_id = request.chain_id.ok_or("Missing chain id")?;
+        let receiver = request.to.ok_or("Contract-creation requests are not supported")?;
+
+        let (gas_price, max_fee_per_gas, max_priority_fee_per_gas) = match request.kind {
+            TransactionKind::Eip1559 => (
+                None,
+                Some(request.max_fee_per_gas.ok_or("Missing max_fee_per_gas")?.0),
+                Some(
+                    request
+                        .max_priority_fee_per_gas
+                        .ok_or("Missing max_priority_fee_per_gas")?
+                        .0,
+                ),
+            ),
+            TransactionKind::Legacy | TransactionKind::Eip2930 => {
+                (Some(request.gas_price.ok_or("Missing gas_price")?.0), None, None)
+            }
+        };
+
+        Ok(Self {
+            chain_id,
+            receiver,
+            nonce: request.nonce,
+            value: request.value.0,
+            gas: request.gas.0,
+            data: request.data,
+            gas_price,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            access_list: request.access_list,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ethers_core::{types::U256, utils::rlp::RlpStream};
+
+    use super::*;
+    use crate::decode_transaction_request;
+
+    /// Encode a type-0x02 envelope, optionally signed and optionally carrying a
+    /// single chain-tagged access-list entry.
+    fn encode(signed: bool, access_chain: Option<u64>) -> String {
+        let mut stream = RlpStream::new_list(if signed { 12 } else { 9 });
+        stream.append(&1u64); // chainId
+        stream.append(&7u64); // nonce
+        stream.append(&U256::from(1u64)); // maxPriority
+        stream.append(&U256::from(100u64)); // maxFee
+        stream.append(&U256::from(21_000u64)); // gas
+        stream.append(&[0x33u8; 20].as_slice()); // to
+        stream.append(&U256::zero()); // value
+        stream.append(&Vec::<u8>::new()); // data
+        match access_chain {
+            Some(chain_id) => {
+                stream.begin_list(1);
+                stream.begin_list(3);
+                stream.append(&chain_id);
+                stream.append(&[0x44u8; 20].as_slice());
+                stream.begin_list(0);
+            }
+            None => {
+                stream.begin_list(0);
+            }
+        }
+        if signed {
+            stream.append(&0u8);
+            stream.append(&U256::from(1u64));
+            stream.append(&U256::from(2u64));
+        }
+        let mut bytes = vec![0x02];
+        bytes.extend_from_slice(stream.as_raw());
+        hex::encode(bytes)
+    }
+
+    #[test]
+    fn gas_price_returns_type_2_ceiling() {
+        let request = ValidTransactionRequest::try_from(decode_transaction_request(&encode(
+            false, None,
+        )))
+        .unwrap();
+        assert_eq!(request.gas_price(), U256::from(100));
+    }
+
+    #[test]
+    fn try_from_rejects_signed_payload() {
+        let result = ValidTransactionRequest::try_from(decode_transaction_request(&encode(
+            true, None,
+        )));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn access_list_survives_signing_round_trip() {
+        let request =
+            ValidTransactionRequest::try_from(decode_transaction_request(&encode(false, Some(42))))
+                .unwrap();
+        assert_eq!(request.access_list_chain_ids(), vec![42]);
+
+        // Re-encoding for the signer must preserve the chain-tagged entry.
+        let payload = request.into_signing_payload();
+        let reparsed =
+            ValidTransactionRequest::try_from(decode_transaction_request(&hex::encode(payload)))
+                .unwrap();
+        assert_eq!(reparsed.access_list_chain_ids(), vec![42]);
+    }
+}